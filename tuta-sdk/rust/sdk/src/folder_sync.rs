@@ -0,0 +1,204 @@
+use crate::entities::tutanota::Mail;
+use crate::generated_id::GeneratedId;
+use crate::{IdTupleGenerated, ListLoadDirection};
+
+/// How many mails [`MailFacade::sync_folder`] fetches per page.
+const SYNC_PAGE_SIZE: usize = 100;
+
+/// An opaque resume point for [`MailFacade::sync_folder`].
+///
+/// Clients should not construct or inspect this directly; they just hold on to whatever
+/// `sync_folder` last returned and pass it back in on the next call to continue syncing from
+/// where they left off.
+///
+/// A sync goes through two phases:
+/// - `Crawling` pages backwards (DESC) from the newest mail, accumulating every id it has seen
+///   so far in `seen_during_crawl`. Removals can't be known yet, since most of the folder hasn't
+///   been walked.
+/// - Once a short page signals the crawl has reached the end of the folder, it turns into
+///   `Delta`, parked at the newest id observed (`high_water_mark`). Every later call only asks
+///   for mails after that point (ASC), so repeated delta syncs are cheap instead of re-walking
+///   the whole folder from `initial_cursor` again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyncCursor {
+	Crawling {
+		anchor_id: GeneratedId,
+		seen_during_crawl: Vec<IdTupleGenerated>,
+	},
+	Delta {
+		high_water_mark: GeneratedId,
+	},
+}
+
+impl SyncCursor {
+	/// A cursor starting an initial full crawl from the newest mail, paging backwards.
+	fn initial() -> Self {
+		SyncCursor::Crawling {
+			anchor_id: GeneratedId::max_id(),
+			seen_during_crawl: Vec::new(),
+		}
+	}
+
+	/// The anchor id and direction to pass to `load_range` for this step.
+	fn fetch_args(&self) -> (GeneratedId, ListLoadDirection) {
+		match self {
+			SyncCursor::Crawling { anchor_id, .. } => (anchor_id.clone(), ListLoadDirection::DESC),
+			SyncCursor::Delta { high_water_mark } => (high_water_mark.clone(), ListLoadDirection::ASC),
+		}
+	}
+}
+
+/// The result of one [`MailFacade::sync_folder`] page.
+pub struct FolderSyncResult {
+	pub new_or_updated: Vec<Mail>,
+	pub removed: Vec<IdTupleGenerated>,
+	/// The cursor to pass to the next `sync_folder` call. Always `Some`: a mail folder is
+	/// open-ended, so there is no terminal state, only the crawling/delta transition captured by
+	/// [`SyncCursor`].
+	pub next_cursor: Option<SyncCursor>,
+}
+
+/// What happened when folding a freshly fetched page into the cursor's state.
+pub(crate) enum CursorAdvance {
+	/// Still mid-crawl (or steady-state delta); nothing can yet be said about removals.
+	Continuing(SyncCursor),
+	/// The crawl just reached the end of the folder. `seen` is every id observed across the
+	/// *entire* crawl, not just this page, so the caller can diff it against the full `UidIndex`
+	/// to find mails that were removed without mistaking "not on this page" for "removed".
+	CrawlComplete {
+		seen: Vec<IdTupleGenerated>,
+		next: SyncCursor,
+	},
+}
+
+pub(crate) fn initial_cursor() -> SyncCursor {
+	SyncCursor::initial()
+}
+
+pub(crate) fn page_size() -> usize {
+	SYNC_PAGE_SIZE
+}
+
+pub(crate) fn fetch_args(cursor: &SyncCursor) -> (GeneratedId, ListLoadDirection) {
+	cursor.fetch_args()
+}
+
+pub(crate) fn advance(cursor: SyncCursor, page: &[Mail], page_size: usize) -> CursorAdvance {
+	match cursor {
+		SyncCursor::Crawling {
+			anchor_id: _,
+			mut seen_during_crawl,
+		} => {
+			seen_during_crawl.extend(page.iter().map(|mail| mail._id.clone()));
+
+			if page.len() < page_size {
+				let high_water_mark = seen_during_crawl
+					.iter()
+					.map(IdTupleGenerated::element_id)
+					.max()
+					.cloned()
+					.unwrap_or_else(GeneratedId::min_id);
+				CursorAdvance::CrawlComplete {
+					seen: seen_during_crawl,
+					next: SyncCursor::Delta { high_water_mark },
+				}
+			} else {
+				let anchor_id = page
+					.last()
+					.expect("a full page is never empty")
+					._id
+					.element_id()
+					.clone();
+				CursorAdvance::Continuing(SyncCursor::Crawling {
+					anchor_id,
+					seen_during_crawl,
+				})
+			}
+		},
+		SyncCursor::Delta { high_water_mark } => {
+			let high_water_mark = page
+				.last()
+				.map(|mail| mail._id.element_id().clone())
+				.unwrap_or(high_water_mark);
+			CursorAdvance::Continuing(SyncCursor::Delta { high_water_mark })
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn mail_with_id(element_id: GeneratedId) -> Mail {
+		Mail {
+			_id: IdTupleGenerated::new(GeneratedId::test_random(), element_id),
+			..Mail::default()
+		}
+	}
+
+	#[test]
+	fn initial_cursor_starts_crawling_from_max_id() {
+		let cursor = initial_cursor();
+		let (anchor_id, direction) = fetch_args(&cursor);
+		assert_eq!(anchor_id, GeneratedId::max_id());
+		assert_eq!(direction, ListLoadDirection::DESC);
+	}
+
+	#[test]
+	fn short_page_completes_the_crawl_and_reports_everything_seen() {
+		let cursor = initial_cursor();
+		let page = vec![mail_with_id(GeneratedId::test_random())];
+
+		match advance(cursor, &page, 100) {
+			CursorAdvance::CrawlComplete { seen, next } => {
+				assert_eq!(seen, vec![page[0]._id.clone()]);
+				assert!(matches!(next, SyncCursor::Delta { .. }));
+			},
+			CursorAdvance::Continuing(_) => panic!("expected crawl to complete"),
+		}
+	}
+
+	#[test]
+	fn full_page_keeps_crawling_and_accumulates_seen_ids_across_calls() {
+		let cursor = initial_cursor();
+		let first_page = vec![
+			mail_with_id(GeneratedId::test_random()),
+			mail_with_id(GeneratedId::test_random()),
+		];
+
+		let cursor = match advance(cursor, &first_page, 2) {
+			CursorAdvance::Continuing(next) => next,
+			CursorAdvance::CrawlComplete { .. } => panic!("a full page must not complete the crawl"),
+		};
+
+		// A second, short page arrives and completes the crawl; a naive single-page diff would treat
+		// the first page's mails as removed here, even though they're simply not on this page.
+		let second_page = vec![mail_with_id(GeneratedId::test_random())];
+		match advance(cursor, &second_page, 2) {
+			CursorAdvance::CrawlComplete { seen, .. } => {
+				assert_eq!(seen.len(), 3);
+				assert!(seen.contains(&first_page[0]._id));
+				assert!(seen.contains(&first_page[1]._id));
+				assert!(seen.contains(&second_page[0]._id));
+			},
+			CursorAdvance::Continuing(_) => panic!("short second page must complete the crawl"),
+		}
+	}
+
+	#[test]
+	fn delta_cursor_advances_high_water_mark_and_never_reports_removals_itself() {
+		let high_water_mark = GeneratedId::test_random();
+		let cursor = SyncCursor::Delta {
+			high_water_mark: high_water_mark.clone(),
+		};
+		let new_id = GeneratedId::test_random();
+		let page = vec![mail_with_id(new_id.clone())];
+
+		match advance(cursor, &page, 100) {
+			CursorAdvance::Continuing(SyncCursor::Delta {
+				high_water_mark: advanced,
+			}) => assert_eq!(advanced, new_id),
+			_ => panic!("delta cursor must stay in delta mode"),
+		}
+	}
+}
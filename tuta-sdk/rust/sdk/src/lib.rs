@@ -0,0 +1,6 @@
+pub mod folder_sync;
+pub mod mail_facade;
+pub mod mail_search;
+pub mod mime_section;
+mod rfc822;
+pub mod uid_index;
@@ -1,18 +1,38 @@
 #[cfg_attr(test, mockall_double::double)]
 use crate::crypto_entity_client::CryptoEntityClient;
 use crate::entities::tutanota::{
-	Mail, MailBox, MailFolder, MailboxGroupRoot, SimpleMoveMailPostIn, UnreadMailStatePostIn,
+	Mail, MailAddress, MailBox, MailFolder, MailboxGroupRoot, MoveMailPostIn, SimpleMoveMailPostIn,
+	UnreadMailStatePostIn,
 };
+use crate::folder_sync::{self, CursorAdvance, FolderSyncResult, SyncCursor};
 use crate::folder_system::{FolderSystem, MailSetKind};
 use crate::generated_id::GeneratedId;
 use crate::groups::GroupType;
+use crate::mail_search::SearchKey;
+use crate::mime_section::{ByteRange, MailPart, MimeSection};
+use crate::rfc822;
 #[cfg_attr(test, mockall_double::double)]
 use crate::services::service_executor::ResolvingServiceExecutor;
-use crate::services::tutanota::{SimpleMoveMailService, UnreadMailStateService};
+use crate::services::tutanota::{MoveMailService, SimpleMoveMailService, UnreadMailStateService};
+use crate::uid_index::{MailFlags, UidIndex};
 #[cfg_attr(test, mockall_double::double)]
 use crate::user_facade::UserFacade;
 use crate::{ApiCallError, IdTupleGenerated, ListLoadDirection};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A `Mail` as returned from a folder load, together with its stable UID and current flags from
+/// that folder's [`UidIndex`].
+#[derive(Clone, Debug)]
+pub struct IndexedMail {
+	pub mail: Mail,
+	pub uid: u32,
+	/// The [`UidIndex::uid_validity`] generation `uid` was assigned under. Callers must discard
+	/// any UID they cached for this folder once this changes, since the index has been reset and
+	/// `uid` may now mean something different than before.
+	pub uid_validity: u32,
+	pub flags: MailFlags,
+}
 
 /// Provides high level functions to manipulate mail entities via the REST API
 #[derive(uniffi::Object)]
@@ -20,6 +40,8 @@ pub struct MailFacade {
 	crypto_entity_client: Arc<CryptoEntityClient>,
 	user_facade: Arc<UserFacade>,
 	service_executor: Arc<ResolvingServiceExecutor>,
+	/// One `UidIndex` per folder, keyed by the folder's mail list id.
+	uid_indices: Mutex<HashMap<GeneratedId, UidIndex>>,
 }
 
 impl MailFacade {
@@ -32,6 +54,7 @@ impl MailFacade {
 			crypto_entity_client,
 			service_executor,
 			user_facade,
+			uid_indices: Mutex::new(HashMap::new()),
 		}
 	}
 }
@@ -82,11 +105,11 @@ impl MailFacade {
 	pub async fn load_mails_in_folder(
 		&self,
 		folder: &MailFolder,
-	) -> Result<Vec<Mail>, ApiCallError> {
+	) -> Result<Vec<IndexedMail>, ApiCallError> {
 		// TODO: real arguments
 		// TODO: this is a placeholder impl that doesn't work with mail sets
 		let mail_list_id = &folder.mails;
-		let mails = self
+		let mails: Vec<Mail> = self
 			.crypto_entity_client
 			.load_range(
 				mail_list_id,
@@ -95,7 +118,38 @@ impl MailFacade {
 				ListLoadDirection::DESC,
 			)
 			.await?;
-		Ok(mails)
+
+		let mut uid_indices = self.uid_indices.lock().unwrap();
+		let index = uid_indices
+			.entry(mail_list_id.clone())
+			.or_insert_with(|| UidIndex::new(1));
+
+		// This only ever fetches the newest 20 mails (see the TODO above), so a mail simply
+		// pushed past the top of that window by newer arrivals is not "gone" - forgetting it here
+		// would hand it a brand new UID if it later re-enters the window. The one case this
+		// window *can* tell apart from "not on this page": since it is always anchored at
+		// max_id, an empty result means the folder has nothing left to page through at all, so
+		// the whole index is reset and clients are told to resync via the bumped uid_validity.
+		// Reliable removal detection for the general case is sync_folder's job, which walks the
+		// whole folder before diffing.
+		if mails.is_empty() && index.known_mails().next().is_some() {
+			index.reset();
+		}
+
+		let indexed = mails
+			.into_iter()
+			.map(|mail| {
+				let uid = index.register(mail._id.clone());
+				let flags = MailFlags {
+					seen: !mail.unread,
+					..index.flags_of(&mail._id).unwrap_or_default()
+				};
+				index.set_flags(&mail._id, flags);
+				IndexedMail { mail, uid, uid_validity: index.uid_validity(), flags }
+			})
+			.collect();
+
+		Ok(indexed)
 	}
 
 	/// Invoke the SimpleMoveMail service to move mail(s) to the first folder of a given folder
@@ -130,6 +184,247 @@ impl MailFacade {
 
 		Ok(())
 	}
+
+	/// Move mail(s) to the first folder of the given kind in the user's mailbox.
+	///
+	/// Unlike [`simple_move_mail`](Self::simple_move_mail), which is restricted to
+	/// `ALLOWED_SIMPLE_MOVE_MAIL_TARGETS`, this drives the full move service and so can target
+	/// any folder kind, e.g. archiving or moving into the inbox or a custom/spam folder.
+	pub async fn move_mails_to_folder(
+		&self,
+		mut mails: Vec<IdTupleGenerated>,
+		target: MailSetKind,
+	) -> Result<(), ApiCallError> {
+		mails.dedup();
+
+		let mailbox = self.load_user_mailbox().await?;
+		let folder_system = self.load_folders_for_mailbox(&mailbox).await?;
+		let target_folder = folder_system.get_system_folder(target).ok_or_else(|| {
+			ApiCallError::internal(format!("No folder of kind {target:?} in mailbox"))
+		})?;
+
+		for mail in mails.chunks(MAX_MAIL_UPDATE_LIMIT) {
+			self.service_executor
+				.post::<MoveMailService>(
+					MoveMailPostIn {
+						_format: 0,
+						targetFolder: target_folder._id.clone(),
+						mails: mail.to_vec(),
+					},
+					Default::default(),
+				)
+				.await?;
+		}
+
+		Ok(())
+	}
+
+	/// Finds mails in `folder` matching `query`.
+	///
+	/// This mirrors IMAP `SEARCH`: `query` is evaluated against each candidate mail's decrypted
+	/// headers, subject, addresses, body, received date and size, with AND/OR/NOT composition
+	/// handled by [`SearchKey`].
+	///
+	/// # Limitations
+	///
+	/// Like [`load_mails_in_folder`](Self::load_mails_in_folder), this currently only considers
+	/// the same placeholder 20-mail window rather than the whole folder.
+	pub async fn search_mails(
+		&self,
+		folder: &MailFolder,
+		query: SearchKey,
+	) -> Result<Vec<IdTupleGenerated>, ApiCallError> {
+		// TODO: this is a placeholder impl that only searches the same window as
+		// load_mails_in_folder, see its TODOs
+		let candidates: Vec<Mail> = self
+			.crypto_entity_client
+			.load_range(
+				&folder.mails,
+				&GeneratedId::max_id(),
+				20,
+				ListLoadDirection::DESC,
+			)
+			.await?;
+
+		Ok(candidates
+			.into_iter()
+			.filter(|candidate| query.matches(candidate))
+			.map(|candidate| candidate._id)
+			.collect())
+	}
+
+	/// Parses a raw RFC822/IMF message and uploads it as a new `Mail` in `folder`.
+	///
+	/// This mirrors IMAP `APPEND`/LMTP delivery: it lets callers inject a message that did not
+	/// originate from a server-side entity, e.g. migration tooling or a local "save to
+	/// drafts/sent" flow that starts from a `.eml` blob.
+	pub async fn append_mail(
+		&self,
+		folder: &MailFolder,
+		raw_imf: Vec<u8>,
+		flags: MailFlags,
+	) -> Result<IdTupleGenerated, ApiCallError> {
+		let parsed = rfc822::parse_message(&raw_imf)
+			.map_err(|e| ApiCallError::internal(format!("Invalid RFC822 message: {e}")))?;
+
+		let mail = Mail {
+			subject: parsed.header("Subject").unwrap_or_default().to_owned(),
+			unread: !flags.seen,
+			flagged: flags.flagged,
+			sender: MailAddress {
+				address: parsed.sender_address().unwrap_or_default(),
+				name: String::new(),
+			},
+			to_recipients: parsed
+				.to_addresses()
+				.into_iter()
+				.map(|address| MailAddress { address, name: String::new() })
+				.collect(),
+			body_text: parsed.text_body(),
+			received_date: parsed.header("Date").and_then(rfc822::parse_date).unwrap_or(0),
+			size: raw_imf.len() as u64,
+			..Mail::default()
+		};
+
+		self.crypto_entity_client.create(&folder.mails, mail).await
+	}
+
+	/// MIME metadata headers considered part of `BODY[MIME]` for a part, as opposed to the
+	/// envelope headers (`To`, `Subject`, ...) that `BODY[HEADER]` returns.
+	const MIME_HEADER_NAMES: &[&'static str] =
+		&["Content-Type", "Content-Transfer-Encoding", "Content-Disposition", "Content-ID"];
+
+	/// Returns a single MIME part of a mail, optionally restricted to a byte range.
+	///
+	/// This lets a client render the text body first and stream attachments on demand: `section`
+	/// addresses a part by its dotted MIME path (e.g. `"1.2"`) or by the `HEADER`/`TEXT`/`MIME`
+	/// pseudo-parts, mirroring IMAP `FETCH BODY[section]`.
+	///
+	/// # Limitations
+	///
+	/// The whole mail is loaded and decrypted via
+	/// [`load_email_by_id_encrypted`](Self::load_email_by_id_encrypted) on every call; only the
+	/// returned bytes are narrowed to the requested part and byte range.
+	///
+	/// Only a single level of MIME structure is modeled: a numeric path is looked up among
+	/// `mail.attachments` by exact `mime_path`, and part `"1"` falls back to the mail's own
+	/// top-level body when no attachment carries that path. Nested multipart paths (e.g. `"2.1"`
+	/// addressing a part inside a nested `multipart/*` attachment) are not supported.
+	pub async fn load_mail_part(
+		&self,
+		id: &IdTupleGenerated,
+		section: MimeSection,
+		byte_range: Option<ByteRange>,
+	) -> Result<MailPart, ApiCallError> {
+		let mail = self.load_email_by_id_encrypted(id).await?;
+
+		let mut part = match section {
+			MimeSection::Header => MailPart {
+				content_type: "message/rfc822-headers".to_owned(),
+				content_transfer_encoding: None,
+				bytes: mail
+					.headers
+					.iter()
+					.map(|header| format!("{}: {}\r\n", header.name, header.value))
+					.collect::<String>()
+					.into_bytes(),
+			},
+			MimeSection::Text => MailPart {
+				content_type: "text/plain".to_owned(),
+				content_transfer_encoding: None,
+				bytes: mail.body_text.into_bytes(),
+			},
+			MimeSection::Mime => MailPart {
+				content_type: "message/rfc822-mime".to_owned(),
+				content_transfer_encoding: None,
+				bytes: mail
+					.headers
+					.iter()
+					.filter(|header| Self::MIME_HEADER_NAMES.iter().any(|name| header.name.eq_ignore_ascii_case(name)))
+					.map(|header| format!("{}: {}\r\n", header.name, header.value))
+					.collect::<String>()
+					.into_bytes(),
+			},
+			MimeSection::Part(path) => {
+				if let Some(attachment) = mail.attachments.into_iter().find(|attachment| attachment.mime_path == path) {
+					MailPart {
+						content_type: attachment.content_type,
+						content_transfer_encoding: attachment.content_transfer_encoding,
+						bytes: attachment.bytes,
+					}
+				} else if path == "1" {
+					MailPart {
+						content_type: "text/plain".to_owned(),
+						content_transfer_encoding: None,
+						bytes: mail.body_text.into_bytes(),
+					}
+				} else {
+					return Err(ApiCallError::internal(format!("No MIME part {path} in mail")));
+				}
+			},
+		};
+
+		if let Some((offset, length)) = byte_range {
+			let start = (offset as usize).min(part.bytes.len());
+			let end = start.saturating_add(length as usize).min(part.bytes.len());
+			part.bytes = part.bytes[start..end].to_vec();
+		}
+
+		Ok(part)
+	}
+
+	/// Pages through a folder in bounded chunks, resuming from `cursor` if given, instead of
+	/// re-fetching [`load_mails_in_folder`]'s fixed window every time.
+	///
+	/// A client does an initial full crawl by calling this repeatedly with the returned
+	/// `next_cursor` (`cursor: None` starts a fresh one); once the crawl reaches the end of the
+	/// folder, the cursor switches to a high-water-mark delta mode and every later call only
+	/// fetches mails newer than the last sync, instead of restarting from the top. `removed` is
+	/// only populated the moment a crawl completes, since that is the first point at which "not
+	/// seen yet" can be told apart from "no longer in the folder".
+	pub async fn sync_folder(
+		&self,
+		folder: &MailFolder,
+		cursor: Option<SyncCursor>,
+	) -> Result<FolderSyncResult, ApiCallError> {
+		let cursor = cursor.unwrap_or_else(folder_sync::initial_cursor);
+		let page_size = folder_sync::page_size();
+		let (anchor_id, direction) = folder_sync::fetch_args(&cursor);
+
+		let page: Vec<Mail> = self
+			.crypto_entity_client
+			.load_range(&folder.mails, &anchor_id, page_size, direction)
+			.await?;
+
+		let mut uid_indices = self.uid_indices.lock().unwrap();
+		let index = uid_indices
+			.entry(folder.mails.clone())
+			.or_insert_with(|| UidIndex::new(1));
+		for mail in &page {
+			index.register(mail._id.clone());
+		}
+
+		let (removed, next_cursor) = match folder_sync::advance(cursor, &page, page_size) {
+			CursorAdvance::Continuing(next) => (Vec::new(), next),
+			CursorAdvance::CrawlComplete { seen, next } => {
+				let removed: Vec<IdTupleGenerated> = index
+					.known_mails()
+					.filter(|known| !seen.contains(known))
+					.cloned()
+					.collect();
+				for mail_id in &removed {
+					index.forget(mail_id);
+				}
+				(removed, next)
+			},
+		};
+
+		Ok(FolderSyncResult {
+			new_or_updated: page,
+			removed,
+			next_cursor: Some(next_cursor),
+		})
+	}
 }
 
 #[uniffi::export]
@@ -184,12 +479,20 @@ impl MailFacade {
 mod tests {
 	use super::UnreadMailStatePostIn;
 	use crate::crypto_entity_client::MockCryptoEntityClient;
-	use crate::entities::tutanota::SimpleMoveMailPostIn;
+	use crate::entities::tutanota::{
+		FolderLists, GroupMembership, Mail, MailAttachment, MailBox, MailFolder, MailboxGroupRoot,
+		MoveMailPostIn, SimpleMoveMailPostIn, User,
+	};
 	use crate::folder_system::MailSetKind;
 	use crate::generated_id::GeneratedId;
+	use crate::groups::GroupType;
 	use crate::mail_facade::MailFacade;
+	use crate::mail_search::SearchKey;
+	use crate::folder_sync::SyncCursor;
+	use crate::mime_section::MimeSection;
+	use crate::uid_index::MailFlags;
 	use crate::services::service_executor::MockResolvingServiceExecutor;
-	use crate::services::tutanota::{SimpleMoveMailService, UnreadMailStateService};
+	use crate::services::tutanota::{MoveMailService, SimpleMoveMailService, UnreadMailStateService};
 	use crate::user_facade::MockUserFacade;
 	use crate::IdTupleGenerated;
 	use mockall::predicate::{always, eq};
@@ -379,6 +682,497 @@ mod tests {
 		facade.trash_mails(mails).await.unwrap();
 	}
 
+	#[tokio::test]
+	async fn move_mails_to_folder_resolves_target_via_full_move_service() {
+		let mails = generate_id_tuples(60);
+		let mail_group_id = GeneratedId::test_random();
+		let mailbox_id = GeneratedId::test_random();
+		let folders_list_id = GeneratedId::test_random();
+		let archive_folder_id = IdTupleGenerated::new(folders_list_id.clone(), GeneratedId::test_random());
+
+		let mut user_facade = MockUserFacade::default();
+		user_facade.expect_get_user().returning({
+			let mail_group_id = mail_group_id.clone();
+			move || User {
+				memberships: vec![GroupMembership {
+					group: mail_group_id.clone(),
+					group_type: GroupType::Mail,
+					..GroupMembership::default()
+				}],
+				..User::default()
+			}
+		});
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load::<MailboxGroupRoot, GeneratedId>()
+			.with(eq(mail_group_id))
+			.returning({
+				let mailbox_id = mailbox_id.clone();
+				move |_| {
+					Ok(MailboxGroupRoot {
+						mailbox: mailbox_id.clone(),
+						..MailboxGroupRoot::default()
+					})
+				}
+			});
+		crypto_entity_client
+			.expect_load::<MailBox, GeneratedId>()
+			.with(eq(mailbox_id))
+			.returning({
+				let folders_list_id = folders_list_id.clone();
+				move |_| {
+					Ok(MailBox {
+						folders: Some(FolderLists {
+							folders: folders_list_id.clone(),
+						}),
+						..MailBox::default()
+					})
+				}
+			});
+		crypto_entity_client.expect_load_range::<MailFolder>().returning({
+			let archive_folder_id = archive_folder_id.clone();
+			move |_, _, _, _| {
+				Ok(vec![MailFolder {
+					_id: archive_folder_id.clone(),
+					folder_type: MailSetKind::Archive as i64,
+					..MailFolder::default()
+				}])
+			}
+		});
+
+		let mut executor = MockResolvingServiceExecutor::default();
+		let first_invocation = MoveMailPostIn {
+			_format: 0,
+			targetFolder: archive_folder_id.clone(),
+			mails: mails[..50].to_vec(),
+		};
+		let second_invocation = MoveMailPostIn {
+			_format: 0,
+			targetFolder: archive_folder_id.clone(),
+			mails: mails[50..].to_vec(),
+		};
+		executor
+			.expect_post::<MoveMailService>()
+			.with(eq(first_invocation), always())
+			.returning(|_, _| Ok(()));
+		executor
+			.expect_post::<MoveMailService>()
+			.with(eq(second_invocation), always())
+			.returning(|_, _| Ok(()));
+
+		let facade = MailFacade::new(Arc::new(crypto_entity_client), Arc::new(user_facade), Arc::new(executor));
+		facade.move_mails_to_folder(mails, MailSetKind::Archive).await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn load_mails_in_folder_does_not_forget_a_mail_pushed_out_of_the_window() {
+		let mails_list_id = GeneratedId::test_random();
+		let folder = MailFolder {
+			mails: mails_list_id.clone(),
+			..MailFolder::default()
+		};
+
+		let pushed_out = Mail {
+			_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+			..Mail::default()
+		};
+		let rest: Vec<Mail> = (0..19)
+			.map(|_| Mail {
+				_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+				..Mail::default()
+			})
+			.collect();
+		let newest = Mail {
+			_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+			..Mail::default()
+		};
+
+		let mut first_window = vec![pushed_out.clone()];
+		first_window.extend(rest.iter().cloned());
+		let mut second_window = rest.clone();
+		second_window.push(newest);
+		let mut third_window = vec![pushed_out.clone()];
+		third_window.extend(rest.iter().cloned().take(18));
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load_range::<Mail>()
+			.times(1)
+			.returning(move |_, _, _, _| Ok(first_window.clone()));
+		crypto_entity_client
+			.expect_load_range::<Mail>()
+			.times(1)
+			.returning(move |_, _, _, _| Ok(second_window.clone()));
+		crypto_entity_client
+			.expect_load_range::<Mail>()
+			.times(1)
+			.returning(move |_, _, _, _| Ok(third_window.clone()));
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let first = facade.load_mails_in_folder(&folder).await.unwrap();
+		let first_uid = first
+			.iter()
+			.find(|indexed| indexed.mail._id == pushed_out._id)
+			.expect("pushed_out is in the first window")
+			.uid;
+
+		// It drops out of the window here - it must not be forgotten from the index.
+		let second = facade.load_mails_in_folder(&folder).await.unwrap();
+		assert!(second.iter().all(|indexed| indexed.mail._id != pushed_out._id));
+
+		// Once it re-enters the window, it must keep the same UID, not a freshly assigned one.
+		let third = facade.load_mails_in_folder(&folder).await.unwrap();
+		let third_uid = third
+			.iter()
+			.find(|indexed| indexed.mail._id == pushed_out._id)
+			.expect("pushed_out is back in the third window")
+			.uid;
+		assert_eq!(first_uid, third_uid);
+	}
+
+	#[tokio::test]
+	async fn load_mails_in_folder_resets_the_index_once_the_window_comes_back_empty() {
+		let mails_list_id = GeneratedId::test_random();
+		let folder = MailFolder {
+			mails: mails_list_id.clone(),
+			..MailFolder::default()
+		};
+		let mail = Mail {
+			_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+			..Mail::default()
+		};
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client.expect_load_range::<Mail>().times(1).returning({
+			let mail = mail.clone();
+			move |_, _, _, _| Ok(vec![mail.clone()])
+		});
+		crypto_entity_client
+			.expect_load_range::<Mail>()
+			.times(1)
+			.returning(|_, _, _, _| Ok(Vec::new()));
+		crypto_entity_client.expect_load_range::<Mail>().times(1).returning({
+			let mail = mail.clone();
+			move |_, _, _, _| Ok(vec![mail.clone()])
+		});
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let first = facade.load_mails_in_folder(&folder).await.unwrap();
+		assert_eq!(first[0].uid_validity, 1);
+
+		let empty = facade.load_mails_in_folder(&folder).await.unwrap();
+		assert!(empty.is_empty());
+
+		let after_reset = facade.load_mails_in_folder(&folder).await.unwrap();
+		assert_eq!(after_reset[0].uid, 1);
+		assert_eq!(after_reset[0].uid_validity, 2);
+	}
+
+	#[tokio::test]
+	async fn search_mails_filters_by_query() {
+		let mails_list_id = GeneratedId::test_random();
+		let folder = MailFolder {
+			mails: mails_list_id.clone(),
+			..MailFolder::default()
+		};
+
+		let matching_id = IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random());
+		let other_id = IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random());
+		let matching_mail = Mail {
+			_id: matching_id.clone(),
+			subject: "Quarterly Report".to_owned(),
+			..Mail::default()
+		};
+		let other_mail = Mail {
+			_id: other_id.clone(),
+			subject: "Lunch plans".to_owned(),
+			..Mail::default()
+		};
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client.expect_load_range::<Mail>().returning({
+			let matching_mail = matching_mail.clone();
+			let other_mail = other_mail.clone();
+			move |_, _, _, _| Ok(vec![matching_mail.clone(), other_mail.clone()])
+		});
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let results = facade
+			.search_mails(&folder, SearchKey::Subject("quarterly".to_owned()))
+			.await
+			.unwrap();
+
+		assert_eq!(results, vec![matching_id]);
+	}
+
+	#[tokio::test]
+	async fn append_mail_parses_and_uploads_the_message() {
+		let mails_list_id = GeneratedId::test_random();
+		let folder = MailFolder {
+			mails: mails_list_id.clone(),
+			..MailFolder::default()
+		};
+		let new_id = IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random());
+
+		let raw = b"Subject: Hello\r\nFrom: jane@example.com\r\nTo: bob@example.com\r\nDate: Mon, 02 Jan 2006 15:04:05 -0700\r\n\r\nHi Bob.".to_vec();
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client.expect_create::<Mail>().with(eq(mails_list_id), always()).returning({
+			let new_id = new_id.clone();
+			move |_, mail: Mail| {
+				assert_eq!(mail.subject, "Hello");
+				assert_eq!(mail.sender.address, "jane@example.com");
+				assert_eq!(mail.to_recipients.len(), 1);
+				assert_eq!(mail.to_recipients[0].address, "bob@example.com");
+				assert_eq!(mail.body_text, "Hi Bob.");
+				assert_eq!(mail.received_date, 1_136_239_445_000);
+				Ok(new_id.clone())
+			}
+		});
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let result = facade
+			.append_mail(&folder, raw, MailFlags { seen: true, ..MailFlags::default() })
+			.await
+			.unwrap();
+
+		assert_eq!(result, new_id);
+	}
+
+	#[tokio::test]
+	async fn load_mail_part_text_returns_body() {
+		let id = IdTupleGenerated::new(GeneratedId::test_random(), GeneratedId::test_random());
+		let mail = Mail {
+			_id: id.clone(),
+			body_text: "Hello there".to_owned(),
+			..Mail::default()
+		};
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load::<Mail, IdTupleGenerated>()
+			.with(eq(id.clone()))
+			.returning(move |_| Ok(mail.clone()));
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let part = facade.load_mail_part(&id, MimeSection::Text, None).await.unwrap();
+		assert_eq!(part.bytes, b"Hello there");
+	}
+
+	#[tokio::test]
+	async fn load_mail_part_finds_attachment_by_mime_path() {
+		let id = IdTupleGenerated::new(GeneratedId::test_random(), GeneratedId::test_random());
+		let mail = Mail {
+			_id: id.clone(),
+			attachments: vec![MailAttachment {
+				mime_path: "2".to_owned(),
+				content_type: "image/png".to_owned(),
+				content_transfer_encoding: Some("base64".to_owned()),
+				bytes: vec![1, 2, 3],
+				..MailAttachment::default()
+			}],
+			..Mail::default()
+		};
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load::<Mail, IdTupleGenerated>()
+			.with(eq(id.clone()))
+			.returning(move |_| Ok(mail.clone()));
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let part = facade
+			.load_mail_part(&id, MimeSection::Part("2".to_owned()), None)
+			.await
+			.unwrap();
+		assert_eq!(part.content_type, "image/png");
+		assert_eq!(part.bytes, vec![1, 2, 3]);
+	}
+
+	#[tokio::test]
+	async fn load_mail_part_part_one_falls_back_to_top_level_body() {
+		let id = IdTupleGenerated::new(GeneratedId::test_random(), GeneratedId::test_random());
+		let mail = Mail {
+			_id: id.clone(),
+			body_text: "Top level body".to_owned(),
+			..Mail::default()
+		};
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load::<Mail, IdTupleGenerated>()
+			.with(eq(id.clone()))
+			.returning(move |_| Ok(mail.clone()));
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let part = facade
+			.load_mail_part(&id, MimeSection::Part("1".to_owned()), None)
+			.await
+			.unwrap();
+		assert_eq!(part.bytes, b"Top level body");
+	}
+
+	#[tokio::test]
+	async fn load_mail_part_applies_byte_range() {
+		let id = IdTupleGenerated::new(GeneratedId::test_random(), GeneratedId::test_random());
+		let mail = Mail {
+			_id: id.clone(),
+			body_text: "Hello there".to_owned(),
+			..Mail::default()
+		};
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load::<Mail, IdTupleGenerated>()
+			.with(eq(id.clone()))
+			.returning(move |_| Ok(mail.clone()));
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let part = facade
+			.load_mail_part(&id, MimeSection::Text, Some((0, 5)))
+			.await
+			.unwrap();
+		assert_eq!(part.bytes, b"Hello");
+	}
+
+	#[tokio::test]
+	async fn sync_folder_does_not_report_a_still_present_mail_as_removed_across_pages() {
+		let mails_list_id = GeneratedId::test_random();
+		let folder = MailFolder {
+			mails: mails_list_id.clone(),
+			..MailFolder::default()
+		};
+
+		let first_page: Vec<Mail> = (0..100)
+			.map(|_| Mail {
+				_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+				..Mail::default()
+			})
+			.collect();
+		let second_page = vec![Mail {
+			_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+			..Mail::default()
+		}];
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load_range::<Mail>()
+			.times(1)
+			.returning({
+				let first_page = first_page.clone();
+				move |_, _, _, _| Ok(first_page.clone())
+			});
+		crypto_entity_client
+			.expect_load_range::<Mail>()
+			.times(1)
+			.returning({
+				let second_page = second_page.clone();
+				move |_, _, _, _| Ok(second_page.clone())
+			});
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		// First (full, 100-mail) page: still mid-crawl, nothing can be reported removed yet.
+		let first_result = facade.sync_folder(&folder, None).await.unwrap();
+		assert!(first_result.removed.is_empty());
+		assert!(matches!(first_result.next_cursor, Some(SyncCursor::Crawling { .. })));
+
+		// Second (short) page completes the crawl. Page 1's 100 mails are still in the folder —
+		// they just aren't on *this* page — so they must not be reported as removed.
+		let second_result = facade.sync_folder(&folder, first_result.next_cursor).await.unwrap();
+		assert!(second_result.removed.is_empty());
+		assert!(matches!(second_result.next_cursor, Some(SyncCursor::Delta { .. })));
+	}
+
+	#[tokio::test]
+	async fn sync_folder_reports_removal_once_a_later_crawl_confirms_a_mail_is_gone() {
+		let mails_list_id = GeneratedId::test_random();
+		let folder = MailFolder {
+			mails: mails_list_id.clone(),
+			..MailFolder::default()
+		};
+		let kept = Mail {
+			_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+			..Mail::default()
+		};
+		let removed_mail = Mail {
+			_id: IdTupleGenerated::new(mails_list_id.clone(), GeneratedId::test_random()),
+			..Mail::default()
+		};
+
+		let mut crypto_entity_client = MockCryptoEntityClient::default();
+		crypto_entity_client
+			.expect_load_range::<Mail>()
+			.times(1)
+			.returning({
+				let kept = kept.clone();
+				let removed_mail = removed_mail.clone();
+				move |_, _, _, _| Ok(vec![kept.clone(), removed_mail.clone()])
+			});
+		crypto_entity_client.expect_load_range::<Mail>().times(1).returning({
+			let kept = kept.clone();
+			move |_, _, _, _| Ok(vec![kept.clone()])
+		});
+
+		let facade = MailFacade::new(
+			Arc::new(crypto_entity_client),
+			Arc::new(MockUserFacade::default()),
+			Arc::new(MockResolvingServiceExecutor::default()),
+		);
+
+		let first_crawl = facade.sync_folder(&folder, None).await.unwrap();
+		assert!(first_crawl.removed.is_empty());
+
+		// A brand new full crawl (cursor: None again) no longer sees `removed_mail`.
+		let second_crawl = facade.sync_folder(&folder, None).await.unwrap();
+		assert_eq!(second_crawl.removed, vec![removed_mail._id]);
+	}
+
 	fn generate_id_tuples(amt: usize) -> Vec<IdTupleGenerated> {
 		std::iter::repeat_with(|| {
 			IdTupleGenerated::new(GeneratedId::test_random(), GeneratedId::test_random())
@@ -0,0 +1,137 @@
+use crate::entities::tutanota::Mail;
+
+/// A search criterion, modeled on IMAP's `SEARCH` command.
+///
+/// `SearchKey`s compose into a tree via [`SearchKey::And`], [`SearchKey::Or`] and
+/// [`SearchKey::Not`]; leaves match against a decrypted [`Mail`]'s flags, headers or body.
+/// `Before`/`Since` compare against the mail's received date as a Unix timestamp in
+/// milliseconds, matching the rest of the SDK's date representation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SearchKey {
+	And(Vec<SearchKey>),
+	Or(Box<SearchKey>, Box<SearchKey>),
+	Not(Box<SearchKey>),
+	Seen,
+	Unseen,
+	Flagged,
+	Header(String, String),
+	Subject(String),
+	From(String),
+	To(String),
+	Body(String),
+	Text(String),
+	Before(i64),
+	Since(i64),
+	Larger(u64),
+	Smaller(u64),
+}
+
+impl SearchKey {
+	/// Evaluates this criterion against a decrypted mail.
+	pub(crate) fn matches(&self, mail: &Mail) -> bool {
+		match self {
+			SearchKey::And(keys) => keys.iter().all(|key| key.matches(mail)),
+			SearchKey::Or(left, right) => left.matches(mail) || right.matches(mail),
+			SearchKey::Not(key) => !key.matches(mail),
+			SearchKey::Seen => !mail.unread,
+			SearchKey::Unseen => mail.unread,
+			SearchKey::Flagged => mail.flagged,
+			SearchKey::Header(name, substring) => mail
+				.headers
+				.iter()
+				.any(|header| header.name.eq_ignore_ascii_case(name) && contains(&header.value, substring)),
+			SearchKey::Subject(substring) => contains(&mail.subject, substring),
+			SearchKey::From(substring) => contains(&mail.sender.address, substring),
+			SearchKey::To(substring) => mail
+				.to_recipients
+				.iter()
+				.any(|recipient| contains(&recipient.address, substring)),
+			SearchKey::Body(substring) => contains(&mail.body_text, substring),
+			SearchKey::Text(substring) => {
+				contains(&mail.subject, substring) || contains(&mail.body_text, substring)
+			},
+			SearchKey::Before(date) => mail.received_date < *date,
+			SearchKey::Since(date) => mail.received_date >= *date,
+			SearchKey::Larger(size) => mail.size > *size,
+			SearchKey::Smaller(size) => mail.size < *size,
+		}
+	}
+}
+
+/// Case-insensitive substring match.
+fn contains(haystack: &str, needle: &str) -> bool {
+	haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn mail_with_subject(subject: &str) -> Mail {
+		Mail {
+			subject: subject.to_owned(),
+			..Mail::default()
+		}
+	}
+
+	#[test]
+	fn subject_match_is_case_insensitive() {
+		let mail = mail_with_subject("Re: Quarterly Report");
+		assert!(SearchKey::Subject("quarterly".to_owned()).matches(&mail));
+		assert!(!SearchKey::Subject("invoice".to_owned()).matches(&mail));
+	}
+
+	#[test]
+	fn and_requires_all_keys() {
+		let mail = mail_with_subject("Quarterly Report");
+		let key = SearchKey::And(vec![
+			SearchKey::Subject("quarterly".to_owned()),
+			SearchKey::Subject("report".to_owned()),
+		]);
+		assert!(key.matches(&mail));
+
+		let key = SearchKey::And(vec![
+			SearchKey::Subject("quarterly".to_owned()),
+			SearchKey::Subject("invoice".to_owned()),
+		]);
+		assert!(!key.matches(&mail));
+	}
+
+	#[test]
+	fn or_requires_either_key() {
+		let mail = mail_with_subject("Quarterly Report");
+		let key = SearchKey::Or(
+			Box::new(SearchKey::Subject("invoice".to_owned())),
+			Box::new(SearchKey::Subject("quarterly".to_owned())),
+		);
+		assert!(key.matches(&mail));
+	}
+
+	#[test]
+	fn not_inverts_key() {
+		let mail = mail_with_subject("Quarterly Report");
+		let key = SearchKey::Not(Box::new(SearchKey::Subject("invoice".to_owned())));
+		assert!(key.matches(&mail));
+	}
+
+	#[test]
+	fn seen_and_unseen_are_complementary() {
+		let mut mail = Mail::default();
+		mail.unread = true;
+		assert!(SearchKey::Unseen.matches(&mail));
+		assert!(!SearchKey::Seen.matches(&mail));
+
+		mail.unread = false;
+		assert!(SearchKey::Seen.matches(&mail));
+		assert!(!SearchKey::Unseen.matches(&mail));
+	}
+
+	#[test]
+	fn larger_and_smaller_compare_size() {
+		let mut mail = Mail::default();
+		mail.size = 100;
+		assert!(SearchKey::Larger(50).matches(&mail));
+		assert!(!SearchKey::Smaller(50).matches(&mail));
+		assert!(SearchKey::Smaller(150).matches(&mail));
+	}
+}
@@ -0,0 +1,37 @@
+/// Addresses a single part of a MIME message, either by its dotted IMAP BODYSTRUCTURE path
+/// (e.g. `1.2` for the second part of the first part of a multipart message) or by one of the
+/// IMAP pseudo-parts covering the message's envelope.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MimeSection {
+	/// A MIME part addressed by its dotted path, e.g. `"1"`, `"1.2"`.
+	Part(String),
+	/// The full set of header fields (IMAP `HEADER`).
+	Header,
+	/// The decoded text of the top-level part (IMAP `TEXT`).
+	Text,
+	/// The MIME-specific headers of the top-level part (IMAP `MIME`).
+	Mime,
+}
+
+/// An optional byte range within a [`MimeSection`], as `(offset, length)`.
+pub type ByteRange = (u64, u64);
+
+/// The bytes of a requested [`MimeSection`], together with the content metadata needed to
+/// interpret them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MailPart {
+	pub content_type: String,
+	pub content_transfer_encoding: Option<String>,
+	pub bytes: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn part_paths_are_distinguished_by_dotted_string() {
+		assert_ne!(MimeSection::Part("1".to_owned()), MimeSection::Part("1.2".to_owned()));
+		assert_eq!(MimeSection::Part("1.2".to_owned()), MimeSection::Part("1.2".to_owned()));
+	}
+}
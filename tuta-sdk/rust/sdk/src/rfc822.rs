@@ -0,0 +1,389 @@
+use std::fmt;
+
+/// A raw RFC822/IMF message split into its header fields and body.
+///
+/// This is intentionally lightweight: it covers what [`MailFacade::append_mail`] needs to turn an
+/// externally supplied message blob (IMAP `APPEND`, LMTP delivery, migration exports) into a
+/// `Mail` entity, not a full MIME implementation. It understands a single level of `multipart/*`
+/// splitting (enough to pick the first `text/plain` part of a typical "plain + html [+
+/// attachments]" message) but does not recurse into nested multiparts and does not decode
+/// `Content-Transfer-Encoding` (quoted-printable/base64) on the parts it returns.
+pub struct ParsedMessage {
+	headers: Vec<(String, String)>,
+	body: Vec<u8>,
+}
+
+/// A single part of a `multipart/*` message, with its own header block and body.
+struct MimePart {
+	headers: Vec<(String, String)>,
+	body: Vec<u8>,
+}
+
+impl MimePart {
+	fn header(&self, name: &str) -> Option<&str> {
+		header_value(&self.headers, name)
+	}
+
+	fn content_type(&self) -> &str {
+		self.header("Content-Type")
+			.map(|value| content_type_value(value))
+			.unwrap_or("text/plain")
+	}
+}
+
+#[derive(Debug)]
+pub struct Rfc822ParseError(String);
+
+impl fmt::Display for Rfc822ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+impl std::error::Error for Rfc822ParseError {}
+
+impl ParsedMessage {
+	/// The value of the first header matching `name`, case-insensitively.
+	pub fn header(&self, name: &str) -> Option<&str> {
+		header_value(&self.headers, name)
+	}
+
+	/// Whether the top-level `Content-Type` is `multipart/*`.
+	pub fn is_multipart(&self) -> bool {
+		self.multipart_boundary().is_some()
+	}
+
+	/// The decoded text of the message's plain-text content.
+	///
+	/// For a `multipart/*` message this is the first part whose type is `text/plain`, where a part
+	/// with no `Content-Type` at all counts as `text/plain` per the RFC 2045 default; for a single-part
+	/// message it is the body itself. Either way, this does not decode
+	/// `Content-Transfer-Encoding`, so a `base64`/`quoted-printable` part is returned as-is.
+	pub fn text_body(&self) -> String {
+		match self.multipart_boundary() {
+			Some(boundary) => split_mime_parts(&self.body, boundary)
+				.into_iter()
+				.find(|part| part.content_type().starts_with("text/plain"))
+				.map(|part| String::from_utf8_lossy(&part.body).into_owned())
+				.unwrap_or_default(),
+			None => String::from_utf8_lossy(&self.body).into_owned(),
+		}
+	}
+
+	pub fn raw_body(&self) -> &[u8] {
+		&self.body
+	}
+
+	/// The address (angle-bracket contents, or the raw value if there are none) of the first
+	/// mailbox named in the `From` header.
+	pub fn sender_address(&self) -> Option<String> {
+		self.header("From")
+			.and_then(|value| value.split(',').next())
+			.map(extract_address)
+	}
+
+	/// The addresses of every mailbox named in the `To` header.
+	///
+	/// Splits on `,` without accounting for commas inside a quoted display name (e.g.
+	/// `"Doe, Jane" <jane@example.com>`); good enough for the common case of a plain address list.
+	pub fn to_addresses(&self) -> Vec<String> {
+		self.header("To")
+			.map(|value| value.split(',').map(extract_address).collect())
+			.unwrap_or_default()
+	}
+
+	fn multipart_boundary(&self) -> Option<&str> {
+		let content_type = self.header("Content-Type")?;
+		if !content_type_value(content_type).starts_with("multipart/") {
+			return None;
+		}
+		content_type_param(content_type, "boundary")
+	}
+}
+
+/// Splits a raw RFC822/IMF byte buffer into unfolded headers and a body, per RFC 5322 §2.1-2.2.
+pub fn parse_message(raw: &[u8]) -> Result<ParsedMessage, Rfc822ParseError> {
+	let split_at = find_header_body_boundary(raw)
+		.ok_or_else(|| Rfc822ParseError("message has no blank line separating headers from body".to_owned()))?;
+	let (header_block, body) = raw.split_at(split_at);
+	let body = skip_boundary(body);
+
+	let header_text = String::from_utf8_lossy(header_block);
+	let headers = unfold_and_parse_headers(&header_text);
+
+	Ok(ParsedMessage {
+		headers,
+		body: body.to_vec(),
+	})
+}
+
+/// Parses a `Date` header per RFC 5322 §3.3 into Unix milliseconds.
+///
+/// Handles the common `["Weekday,"] DD Mon YYYY HH:MM[:SS] zone` shape, where `zone` is a numeric
+/// offset (`+0000`) or one of the RFC 822 alphabetic zones (`UT`/`GMT`/`EST`/...). Returns `None`
+/// for anything else rather than guessing.
+pub fn parse_date(date_header: &str) -> Option<i64> {
+	let date_header = date_header.trim();
+	let without_weekday = match date_header.split_once(',') {
+		Some((_, rest)) => rest.trim(),
+		None => date_header,
+	};
+
+	let mut parts = without_weekday.split_whitespace();
+	let day: i64 = parts.next()?.parse().ok()?;
+	let month = month_number(parts.next()?)?;
+	let year: i64 = parts.next()?.parse().ok()?;
+	let time = parts.next()?;
+	let zone = parts.next().unwrap_or("+0000");
+
+	let mut time_parts = time.split(':');
+	let hour: i64 = time_parts.next()?.parse().ok()?;
+	let minute: i64 = time_parts.next()?.parse().ok()?;
+	let second: i64 = match time_parts.next() {
+		Some(seconds) => seconds.parse().ok()?,
+		None => 0,
+	};
+
+	let offset_minutes = parse_zone_offset(zone)?;
+	let days = days_from_civil(year, month, day);
+	let seconds = days * 86_400 + hour * 3600 + minute * 60 + second - offset_minutes * 60;
+	Some(seconds * 1000)
+}
+
+fn month_number(name: &str) -> Option<i64> {
+	const MONTHS: [&str; 12] = [
+		"Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+	];
+	let prefix = name.get(..3)?;
+	MONTHS
+		.iter()
+		.position(|month| month.eq_ignore_ascii_case(prefix))
+		.map(|index| index as i64 + 1)
+}
+
+fn parse_zone_offset(zone: &str) -> Option<i64> {
+	match zone {
+		"UT" | "GMT" | "UTC" | "Z" => Some(0),
+		"EST" => Some(-5 * 60),
+		"EDT" => Some(-4 * 60),
+		"CST" => Some(-6 * 60),
+		"CDT" => Some(-5 * 60),
+		"MST" => Some(-7 * 60),
+		"MDT" => Some(-6 * 60),
+		"PST" => Some(-8 * 60),
+		"PDT" => Some(-7 * 60),
+		_ => {
+			let sign = if zone.starts_with('-') { -1 } else { 1 };
+			let digits = zone.trim_start_matches(['+', '-']);
+			if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+				return None;
+			}
+			let hours: i64 = digits[0..2].parse().ok()?;
+			let minutes: i64 = digits[2..4].parse().ok()?;
+			Some(sign * (hours * 60 + minutes))
+		},
+	}
+}
+
+/// Days since the Unix epoch for a proleptic-Gregorian civil date, via Howard Hinnant's
+/// `days_from_civil` algorithm (the `era` adjustment gives correct floor division for negative
+/// years).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let y = if month <= 2 { year - 1 } else { year };
+	let era = (if y >= 0 { y } else { y - 399 }) / 400;
+	let yoe = y - era * 400;
+	let mp = (month + 9) % 12;
+	let doy = (153 * mp + 2) / 5 + day - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 719_468
+}
+
+/// Extracts the address from a `"Display Name" <addr@example.com>` mailbox, or returns the
+/// trimmed input unchanged if there are no angle brackets.
+fn extract_address(mailbox: &str) -> String {
+	let mailbox = mailbox.trim();
+	match (mailbox.find('<'), mailbox.find('>')) {
+		(Some(start), Some(end)) if start < end => mailbox[start + 1..end].trim().to_owned(),
+		_ => mailbox.to_owned(),
+	}
+}
+
+/// Finds the index of the CRLF-CRLF (or LF-LF) sequence ending the header block.
+fn find_header_body_boundary(raw: &[u8]) -> Option<usize> {
+	raw.windows(4)
+		.position(|window| window == b"\r\n\r\n")
+		.or_else(|| raw.windows(2).position(|window| window == b"\n\n"))
+}
+
+fn skip_boundary(body: &[u8]) -> &[u8] {
+	if let Some(rest) = body.strip_prefix(b"\r\n\r\n") {
+		rest
+	} else if let Some(rest) = body.strip_prefix(b"\n\n") {
+		rest
+	} else {
+		body
+	}
+}
+
+fn unfold_and_parse_headers(header_text: &str) -> Vec<(String, String)> {
+	let mut headers = Vec::new();
+	for line in header_text.split("\r\n").flat_map(|line| line.split('\n')) {
+		if line.starts_with([' ', '\t']) {
+			// Folded continuation of the previous header's value.
+			if let Some((_, value)) = headers.last_mut() {
+				let value: &mut String = value;
+				value.push(' ');
+				value.push_str(line.trim());
+				continue;
+			}
+		}
+		if let Some((name, value)) = line.split_once(':') {
+			headers.push((name.trim().to_owned(), value.trim().to_owned()));
+		}
+	}
+	headers
+}
+
+fn header_value<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+	headers
+		.iter()
+		.find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+		.map(|(_, value)| value.as_str())
+}
+
+/// The MIME type portion of a `Content-Type` header value, e.g. `"multipart/mixed"` out of
+/// `"multipart/mixed; boundary=abc"`.
+fn content_type_value(content_type: &str) -> &str {
+	content_type.split(';').next().unwrap_or(content_type).trim()
+}
+
+/// A `;`-separated parameter of a structured header value, e.g. `boundary` out of
+/// `"multipart/mixed; boundary=\"abc\""`.
+fn content_type_param<'a>(content_type: &'a str, param: &str) -> Option<&'a str> {
+	content_type.split(';').skip(1).find_map(|segment| {
+		let (name, value) = segment.trim().split_once('=')?;
+		if !name.trim().eq_ignore_ascii_case(param) {
+			return None;
+		}
+		Some(value.trim().trim_matches('"'))
+	})
+}
+
+/// Splits a multipart body on its `boundary` delimiter lines per RFC 2046 §5.1, parsing each part
+/// as its own header block + body. Only one level deep: a part that is itself `multipart/*` is
+/// not recursed into.
+fn split_mime_parts(body: &[u8], boundary: &str) -> Vec<MimePart> {
+	let delimiter = format!("--{boundary}");
+	let text = String::from_utf8_lossy(body);
+
+	text.split(&delimiter)
+		// The first chunk is the preamble and the last is whatever follows the closing
+		// delimiter (often just "--"); neither is a real part.
+		.skip(1)
+		.filter_map(|chunk| {
+			let chunk = chunk.strip_prefix("\r\n").or_else(|| chunk.strip_prefix('\n')).unwrap_or(chunk);
+			if chunk.trim().is_empty() || chunk.trim_start().starts_with("--") {
+				return None;
+			}
+			let boundary_index = find_header_body_boundary(chunk.as_bytes())?;
+			let (header_block, part_body) = chunk.as_bytes().split_at(boundary_index);
+			Some(MimePart {
+				headers: unfold_and_parse_headers(&String::from_utf8_lossy(header_block)),
+				body: skip_boundary(part_body).to_vec(),
+			})
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_headers_and_body() {
+		let raw = b"Subject: Hello\r\nFrom: a@example.com\r\n\r\nBody text.";
+		let message = parse_message(raw).unwrap();
+
+		assert_eq!(message.header("Subject"), Some("Hello"));
+		assert_eq!(message.header("From"), Some("a@example.com"));
+		assert_eq!(message.text_body(), "Body text.");
+	}
+
+	#[test]
+	fn header_lookup_is_case_insensitive() {
+		let raw = b"subject: Hello\r\n\r\nBody";
+		let message = parse_message(raw).unwrap();
+		assert_eq!(message.header("Subject"), Some("Hello"));
+	}
+
+	#[test]
+	fn unfolds_continuation_lines() {
+		let raw = b"Subject: Hello\r\n World\r\n\r\nBody";
+		let message = parse_message(raw).unwrap();
+		assert_eq!(message.header("Subject"), Some("Hello World"));
+	}
+
+	#[test]
+	fn rejects_message_without_blank_line() {
+		let raw = b"Subject: Hello";
+		assert!(parse_message(raw).is_err());
+	}
+
+	#[test]
+	fn extracts_sender_and_recipients() {
+		let raw = b"From: \"Jane Doe\" <jane@example.com>\r\nTo: a@example.com, \"Bob\" <b@example.com>\r\n\r\nHi";
+		let message = parse_message(raw).unwrap();
+
+		assert_eq!(message.sender_address(), Some("jane@example.com".to_owned()));
+		assert_eq!(
+			message.to_addresses(),
+			vec!["a@example.com".to_owned(), "b@example.com".to_owned()]
+		);
+	}
+
+	#[test]
+	fn plain_address_without_angle_brackets_is_used_as_is() {
+		let raw = b"From: jane@example.com\r\n\r\nHi";
+		let message = parse_message(raw).unwrap();
+		assert_eq!(message.sender_address(), Some("jane@example.com".to_owned()));
+	}
+
+	#[test]
+	fn detects_multipart_and_picks_first_text_plain_part() {
+		let raw = b"Content-Type: multipart/alternative; boundary=XYZ\r\n\r\n\
+			--XYZ\r\nContent-Type: text/plain\r\n\r\nPlain body.\r\n\
+			--XYZ\r\nContent-Type: text/html\r\n\r\n<p>HTML body.</p>\r\n\
+			--XYZ--\r\n";
+		let message = parse_message(raw).unwrap();
+
+		assert!(message.is_multipart());
+		assert_eq!(message.text_body(), "Plain body.\r\n");
+	}
+
+	#[test]
+	fn single_part_message_is_not_multipart() {
+		let raw = b"Content-Type: text/plain\r\n\r\nJust text.";
+		let message = parse_message(raw).unwrap();
+
+		assert!(!message.is_multipart());
+		assert_eq!(message.text_body(), "Just text.");
+	}
+
+	#[test]
+	fn parses_common_date_format() {
+		let millis = parse_date("Mon, 02 Jan 2006 15:04:05 -0700").unwrap();
+		// 2006-01-02T22:04:05Z
+		assert_eq!(millis, 1_136_239_445_000);
+	}
+
+	#[test]
+	fn parses_date_with_alphabetic_zone() {
+		let millis = parse_date("02 Jan 2006 15:04:05 GMT").unwrap();
+		assert_eq!(millis, 1_136_214_245_000);
+	}
+
+	#[test]
+	fn rejects_unparseable_date() {
+		assert_eq!(parse_date("not a date"), None);
+	}
+}
@@ -0,0 +1,207 @@
+use crate::IdTupleGenerated;
+use std::collections::HashMap;
+
+/// Flags tracked per mail, mirroring the subset of IMAP message flags clients care about.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MailFlags {
+	pub seen: bool,
+	pub flagged: bool,
+	pub answered: bool,
+	pub deleted: bool,
+	pub draft: bool,
+}
+
+struct Entry {
+	uid: u32,
+	flags: MailFlags,
+}
+
+/// Maintains a stable, IMAP-style UID ordering and flag set for the mails of a single folder.
+///
+/// `MailFacade` keeps one `UidIndex` per folder so that repeated loads can be reconciled against
+/// a stable `(uid, flags)` view instead of re-diffing opaque entity lists: newly seen mails are
+/// [`register`](Self::register)ed into monotonically increasing UIDs, mails that disappear are
+/// [`forget`](Self::forget)ted, and flag changes are recorded with [`set_flags`](Self::set_flags).
+pub struct UidIndex {
+	uid_validity: u32,
+	next_uid: u32,
+	by_mail: HashMap<IdTupleGenerated, Entry>,
+	by_uid: HashMap<u32, IdTupleGenerated>,
+}
+
+impl UidIndex {
+	pub fn new(uid_validity: u32) -> Self {
+		UidIndex {
+			uid_validity,
+			next_uid: 1,
+			by_mail: HashMap::new(),
+			by_uid: HashMap::new(),
+		}
+	}
+
+	pub fn uid_validity(&self) -> u32 {
+		self.uid_validity
+	}
+
+	/// The UID currently assigned to `mail_id`, if it is present in the index.
+	pub fn uid_of(&self, mail_id: &IdTupleGenerated) -> Option<u32> {
+		self.by_mail.get(mail_id).map(|entry| entry.uid)
+	}
+
+	/// The current flags for `mail_id`, if it is present in the index.
+	pub fn flags_of(&self, mail_id: &IdTupleGenerated) -> Option<MailFlags> {
+		self.by_mail.get(mail_id).map(|entry| entry.flags)
+	}
+
+	/// The mail currently assigned to `uid`, if any.
+	pub fn mail_of(&self, uid: u32) -> Option<&IdTupleGenerated> {
+		self.by_uid.get(&uid)
+	}
+
+	/// All mails currently tracked by the index, in no particular order.
+	pub fn known_mails(&self) -> impl Iterator<Item = &IdTupleGenerated> {
+		self.by_mail.keys()
+	}
+
+	/// Assigns a fresh, monotonically increasing UID to `mail_id`.
+	///
+	/// If `mail_id` is already registered, its existing UID is returned unchanged; removing it
+	/// with [`forget`](Self::forget) first and registering it again always yields a new UID.
+	pub fn register(&mut self, mail_id: IdTupleGenerated) -> u32 {
+		if let Some(entry) = self.by_mail.get(&mail_id) {
+			return entry.uid;
+		}
+
+		let uid = self.next_uid;
+		self.next_uid += 1;
+		self.by_uid.insert(uid, mail_id.clone());
+		self.by_mail.insert(
+			mail_id,
+			Entry {
+				uid,
+				flags: MailFlags::default(),
+			},
+		);
+		uid
+	}
+
+	/// Removes `mail_id` from the index, if present.
+	pub fn forget(&mut self, mail_id: &IdTupleGenerated) {
+		if let Some(entry) = self.by_mail.remove(mail_id) {
+			self.by_uid.remove(&entry.uid);
+		}
+	}
+
+	/// Updates the flags recorded for `mail_id`. No-op if `mail_id` is not registered.
+	pub fn set_flags(&mut self, mail_id: &IdTupleGenerated, flags: MailFlags) {
+		if let Some(entry) = self.by_mail.get_mut(mail_id) {
+			entry.flags = flags;
+		}
+	}
+
+	/// Clears the index and bumps `uid_validity` so that clients know to discard any previously
+	/// cached UID mapping and resync from scratch.
+	pub fn reset(&mut self) {
+		self.uid_validity = self.uid_validity.wrapping_add(1);
+		self.next_uid = 1;
+		self.by_mail.clear();
+		self.by_uid.clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::generated_id::GeneratedId;
+
+	fn id() -> IdTupleGenerated {
+		IdTupleGenerated::new(GeneratedId::test_random(), GeneratedId::test_random())
+	}
+
+	#[test]
+	fn assigns_increasing_uids() {
+		let mut index = UidIndex::new(1);
+		let a = id();
+		let b = id();
+
+		let uid_a = index.register(a.clone());
+		let uid_b = index.register(b.clone());
+
+		assert_eq!(uid_a, 1);
+		assert_eq!(uid_b, 2);
+		assert_eq!(index.uid_of(&a), Some(1));
+		assert_eq!(index.uid_of(&b), Some(2));
+	}
+
+	#[test]
+	fn re_registering_same_mail_is_idempotent() {
+		let mut index = UidIndex::new(1);
+		let a = id();
+
+		let first = index.register(a.clone());
+		let second = index.register(a.clone());
+
+		assert_eq!(first, second);
+	}
+
+	#[test]
+	fn forgetting_then_registering_yields_new_uid() {
+		let mut index = UidIndex::new(1);
+		let a = id();
+
+		let first = index.register(a.clone());
+		index.forget(&a);
+		let second = index.register(a.clone());
+
+		assert_ne!(first, second);
+		assert_eq!(second, 2);
+	}
+
+	#[test]
+	fn forget_removes_reverse_mapping() {
+		let mut index = UidIndex::new(1);
+		let a = id();
+
+		let uid = index.register(a.clone());
+		index.forget(&a);
+
+		assert_eq!(index.uid_of(&a), None);
+		assert_eq!(index.mail_of(uid), None);
+	}
+
+	#[test]
+	fn set_flags_updates_entry() {
+		let mut index = UidIndex::new(1);
+		let a = id();
+		index.register(a.clone());
+
+		index.set_flags(
+			&a,
+			MailFlags {
+				seen: true,
+				..Default::default()
+			},
+		);
+
+		assert_eq!(
+			index.flags_of(&a),
+			Some(MailFlags {
+				seen: true,
+				..Default::default()
+			})
+		);
+	}
+
+	#[test]
+	fn reset_clears_entries_and_bumps_validity() {
+		let mut index = UidIndex::new(1);
+		let a = id();
+		index.register(a.clone());
+
+		index.reset();
+
+		assert_eq!(index.uid_validity(), 2);
+		assert_eq!(index.uid_of(&a), None);
+		assert_eq!(index.register(a), 1);
+	}
+}